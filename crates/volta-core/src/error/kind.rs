@@ -0,0 +1,78 @@
+use std::fmt;
+
+/// The specific kind of error that occurred, along with any contextual data needed to render a
+/// user-facing message.
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// Thrown when executing an external binary fails
+    BinaryExecError,
+
+    /// Thrown when a command needs a Node platform, but none is available
+    NoPlatform,
+
+    /// Thrown when a global `npm install` is intercepted but there is no platform Volta can use
+    /// to manage it, and strict global handling is enabled.
+    NoGlobalInstalls {
+        /// The spec(s) the user was attempting to install, rendered as they would be passed to
+        /// `volta install`.
+        package: String,
+    },
+
+    /// Thrown when installing a global package through Volta fails
+    PackageInstallFailed {
+        package: String,
+    },
+
+    /// Thrown when removing a Volta-managed global package fails
+    PackageUninstallFailed {
+        package: String,
+    },
+
+    /// Thrown when Volta is unable to enumerate the packages it currently manages
+    ReadPackagesError,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::BinaryExecError => write!(
+                f,
+                "Could not execute command.
+
+Please ensure you have correct permissions and try again."
+            ),
+            ErrorKind::NoPlatform => write!(
+                f,
+                "Could not determine an appropriate Node version.
+
+Use `volta pin node` in a project to select a version (see `volta help pin` for more info)."
+            ),
+            ErrorKind::NoGlobalInstalls { package } => write!(
+                f,
+                "Global package installs are managed by Volta, but no default platform is available.
+
+To have Volta install and manage this package, please run:
+
+    volta install {package}"
+            ),
+            ErrorKind::PackageInstallFailed { package } => write!(
+                f,
+                "Could not install package '{package}'
+
+Please ensure the package name is correct and try again."
+            ),
+            ErrorKind::PackageUninstallFailed { package } => write!(
+                f,
+                "Could not uninstall package '{package}'
+
+Please ensure the package is installed and try again."
+            ),
+            ErrorKind::ReadPackagesError => write!(
+                f,
+                "Could not read the list of installed packages.
+
+Please ensure your Volta directory is accessible and try again."
+            ),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Error types and helpers shared across `volta-core`.
+
+use std::error::Error;
+use std::fmt;
+
+mod kind;
+
+pub use kind::ErrorKind;
+
+/// The result type used throughout `volta-core`.
+pub type Fallible<T> = Result<T, VoltaError>;
+
+/// The top-level error type for Volta, wrapping an `ErrorKind` describing what went wrong.
+#[derive(Debug)]
+pub struct VoltaError {
+    kind: Box<ErrorKind>,
+}
+
+impl VoltaError {
+    /// The `ErrorKind` underlying this error
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for VoltaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+impl Error for VoltaError {}
+
+impl From<ErrorKind> for VoltaError {
+    fn from(kind: ErrorKind) -> Self {
+        VoltaError {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+/// Trait used to attach a Volta `ErrorKind` to a lower-level error, mirroring the
+/// `failure`-style context pattern used elsewhere in the codebase.
+pub trait Context<T> {
+    /// Convert the error in a `Result` to a `VoltaError` with the given `ErrorKind`.
+    fn with_context<F>(self, f: F) -> Fallible<T>
+    where
+        F: FnOnce() -> ErrorKind;
+}
+
+impl<T, E> Context<T> for Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn with_context<F>(self, f: F) -> Fallible<T>
+    where
+        F: FnOnce() -> ErrorKind,
+    {
+        self.map_err(|_| f().into())
+    }
+}
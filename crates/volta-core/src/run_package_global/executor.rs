@@ -0,0 +1,277 @@
+use std::ffi::OsString;
+use std::process::{Command, ExitStatus};
+
+use super::{debug_active_image, npm};
+use crate::command::create_command;
+use crate::error::{Context, ErrorKind, Fallible};
+use crate::platform::Platform;
+use crate::session::Session;
+use crate::tool::package::{self, DirectInstall, PackageManager, UninstallTarget};
+use crate::tool::{PackageSource, Spec};
+
+/// An execution plan for an intercepted command.
+///
+/// A single invocation may expand into several executors (for example, a batch global install),
+/// which run in sequence via [`Executor::Multiple`].
+pub enum Executor {
+    Tool(Box<ToolCommand>),
+    PackageInstall(Box<PackageInstallCommand>),
+    PackageUninstall(Box<PackageUninstallCommand>),
+    InternalInstall(Box<InternalInstallCommand>),
+    InternalUninstall(Box<InternalUninstallCommand>),
+    Multiple(Vec<Executor>),
+}
+
+impl Executor {
+    /// Run the command(s) in this executor to completion.
+    pub fn execute(self, session: &mut Session) -> Fallible<ExitStatus> {
+        match self {
+            Executor::Tool(cmd) => cmd.execute(session),
+            Executor::PackageInstall(cmd) => cmd.execute(session),
+            Executor::PackageUninstall(cmd) => cmd.execute(session),
+            Executor::InternalInstall(cmd) => cmd.execute(session),
+            Executor::InternalUninstall(cmd) => cmd.execute(session),
+            Executor::Multiple(executors) => {
+                let mut status = success_status();
+                for executor in executors {
+                    status = executor.execute(session)?;
+                    if !status.success() {
+                        break;
+                    }
+                }
+                Ok(status)
+            }
+        }
+    }
+}
+
+impl From<ToolCommand> for Executor {
+    fn from(cmd: ToolCommand) -> Self {
+        Executor::Tool(Box::new(cmd))
+    }
+}
+
+impl From<PackageInstallCommand> for Executor {
+    fn from(cmd: PackageInstallCommand) -> Self {
+        Executor::PackageInstall(Box::new(cmd))
+    }
+}
+
+impl From<PackageUninstallCommand> for Executor {
+    fn from(cmd: PackageUninstallCommand) -> Self {
+        Executor::PackageUninstall(Box::new(cmd))
+    }
+}
+
+impl From<InternalInstallCommand> for Executor {
+    fn from(cmd: InternalInstallCommand) -> Self {
+        Executor::InternalInstall(Box::new(cmd))
+    }
+}
+
+impl From<InternalUninstallCommand> for Executor {
+    fn from(cmd: InternalUninstallCommand) -> Self {
+        Executor::InternalUninstall(Box::new(cmd))
+    }
+}
+
+impl From<Vec<Executor>> for Executor {
+    fn from(executors: Vec<Executor>) -> Self {
+        Executor::Multiple(executors)
+    }
+}
+
+/// The kind of tool a [`ToolCommand`] wraps, used to select the correct execution context.
+pub enum ToolKind {
+    Npm,
+}
+
+/// A command that is passed through to an underlying tool, running under the appropriate platform.
+pub struct ToolCommand {
+    command: Command,
+    platform: Option<Platform>,
+    kind: ToolKind,
+}
+
+impl ToolCommand {
+    pub fn new(bin: &str, args: &[OsString], platform: Option<Platform>, kind: ToolKind) -> Self {
+        let mut command = create_command(bin);
+        command.args(args);
+
+        ToolCommand {
+            command,
+            platform,
+            kind,
+        }
+    }
+
+    fn execute(mut self, session: &mut Session) -> Fallible<ExitStatus> {
+        let (path, on_failure) = match self.kind {
+            ToolKind::Npm => npm::execution_context(self.platform, session)?,
+        };
+
+        self.command.env("PATH", path);
+        self.command.status().with_context(move || on_failure)
+    }
+}
+
+/// A global package install performed directly by Volta.
+pub struct PackageInstallCommand {
+    command: Command,
+    installer: DirectInstall,
+    platform: Platform,
+}
+
+impl PackageInstallCommand {
+    pub fn new(
+        name: String,
+        args: &[OsString],
+        platform: Platform,
+        manager: PackageManager,
+    ) -> Fallible<Self> {
+        let installer = DirectInstall::new(name, manager)?;
+
+        Ok(PackageInstallCommand {
+            command: npm_command(args),
+            installer,
+            platform,
+        })
+    }
+
+    pub fn from_source(
+        source: PackageSource,
+        args: &[OsString],
+        platform: Platform,
+        manager: PackageManager,
+    ) -> Fallible<Self> {
+        let installer = DirectInstall::from_source(source, manager)?;
+
+        Ok(PackageInstallCommand {
+            command: npm_command(args),
+            installer,
+            platform,
+        })
+    }
+
+    fn execute(mut self, session: &mut Session) -> Fallible<ExitStatus> {
+        let image = self.platform.checkout(session)?;
+        let path = image.path()?;
+        debug_active_image(&image);
+
+        self.command.env("PATH", path);
+        self.installer.setup_command(&mut self.command);
+
+        let status = self.command.status().with_context(|| ErrorKind::BinaryExecError)?;
+
+        if status.success() {
+            self.installer.complete_install(&image)?;
+        }
+
+        Ok(status)
+    }
+}
+
+/// Removal of a Volta-managed global package.
+pub struct PackageUninstallCommand {
+    target: UninstallTarget,
+}
+
+impl PackageUninstallCommand {
+    pub fn new(name: String, _args: &[OsString]) -> Fallible<Self> {
+        Ok(PackageUninstallCommand {
+            target: UninstallTarget::Package(name),
+        })
+    }
+
+    pub fn from_source(source: PackageSource, _args: &[OsString]) -> Fallible<Self> {
+        Ok(PackageUninstallCommand {
+            target: UninstallTarget::Source(source),
+        })
+    }
+
+    fn execute(self, _session: &mut Session) -> Fallible<ExitStatus> {
+        package::uninstall(&self.target)?;
+        Ok(success_status())
+    }
+}
+
+/// Installation of a Volta-managed tool (Node, npm, pnpm, Yarn) via Volta's internal logic.
+pub struct InternalInstallCommand {
+    tool: Spec,
+}
+
+impl InternalInstallCommand {
+    pub fn new(tool: Spec) -> Self {
+        InternalInstallCommand { tool }
+    }
+
+    fn execute(self, session: &mut Session) -> Fallible<ExitStatus> {
+        install_internal(self.tool, session)?;
+        Ok(success_status())
+    }
+}
+
+/// Removal of a Volta-managed tool via Volta's internal logic.
+pub struct InternalUninstallCommand {
+    tool: Spec,
+}
+
+impl InternalUninstallCommand {
+    pub fn new(tool: Spec) -> Self {
+        InternalUninstallCommand { tool }
+    }
+
+    fn execute(self, session: &mut Session) -> Fallible<ExitStatus> {
+        uninstall_internal(self.tool, session)?;
+        Ok(success_status())
+    }
+}
+
+/// Install a Volta-managed tool using the per-tool internal install logic.
+fn install_internal(tool: Spec, session: &mut Session) -> Fallible<()> {
+    match tool {
+        Spec::Node(version) => crate::tool::node::install(version, session),
+        Spec::Npm(version) => crate::tool::npm::install(version, session),
+        Spec::Pnpm(version) => crate::tool::pnpm::install(version, session),
+        Spec::Yarn(version) => crate::tool::yarn::install(version, session),
+        Spec::Package(name, _) => Err(ErrorKind::PackageInstallFailed { package: name }.into()),
+        Spec::Source(source) => Err(ErrorKind::PackageInstallFailed {
+            package: source.to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Uninstall a Volta-managed tool using the per-tool internal uninstall logic.
+fn uninstall_internal(tool: Spec, session: &mut Session) -> Fallible<()> {
+    match tool {
+        Spec::Node(_) => crate::tool::node::uninstall(session),
+        Spec::Npm(_) => crate::tool::npm::uninstall(session),
+        Spec::Pnpm(_) => crate::tool::pnpm::uninstall(session),
+        Spec::Yarn(_) => crate::tool::yarn::uninstall(session),
+        Spec::Package(name, _) => Err(ErrorKind::PackageUninstallFailed { package: name }.into()),
+        Spec::Source(source) => Err(ErrorKind::PackageUninstallFailed {
+            package: source.to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Build an npm command from the user-provided arguments.
+fn npm_command(args: &[OsString]) -> Command {
+    let mut command = create_command("npm");
+    command.args(args);
+    command
+}
+
+#[cfg(unix)]
+fn success_status() -> ExitStatus {
+    use std::os::unix::process::ExitStatusExt;
+    ExitStatus::from_raw(0)
+}
+
+#[cfg(windows)]
+fn success_status() -> ExitStatus {
+    use std::os::windows::process::ExitStatusExt;
+    ExitStatus::from_raw(0)
+}
@@ -0,0 +1,30 @@
+//! Intercepts global package-manager commands so that Volta can own global installs.
+
+mod executor;
+pub mod npm;
+
+use log::debug;
+
+use crate::platform::Image;
+use crate::tool::Spec;
+
+/// How an intercepted package-manager invocation maps onto Volta's global handling.
+pub(super) enum CommandArg {
+    /// A global install of one or more specs (`npm i -g <specs...>`)
+    GlobalAdd(Vec<Spec>),
+    /// A global uninstall of one or more specs (`npm uninstall -g <specs...>`)
+    GlobalRemove(Vec<Spec>),
+    /// A global update of zero or more specs (`npm update -g [<specs...>]`); an empty list means
+    /// "refresh every package Volta manages".
+    GlobalUpdate(Vec<Spec>),
+    /// Anything else: the command is passed through to the underlying tool unchanged.
+    NotGlobalAdd,
+}
+
+fn debug_active_image(image: &Image) {
+    debug!("Active image: {:?}", image);
+}
+
+fn debug_no_platform() {
+    debug!("No platform available, using system environment");
+}
@@ -1,7 +1,8 @@
 use std::ffi::OsString;
 
 use super::executor::{
-    Executor, InternalInstallCommand, PackageInstallCommand, ToolCommand, ToolKind,
+    Executor, InternalInstallCommand, InternalUninstallCommand, PackageInstallCommand,
+    PackageUninstallCommand, ToolCommand, ToolKind,
 };
 use super::{debug_active_image, debug_no_platform, CommandArg};
 use crate::error::{ErrorKind, Fallible};
@@ -19,16 +20,80 @@ use crate::tool::Spec;
 /// - Otherwise, we allow npm to execute the command as usual
 pub(super) fn command(args: &[OsString], session: &mut Session) -> Fallible<Executor> {
     match check_npm_install(args) {
-        CommandArg::GlobalAdd(Spec::Package(name, _)) => {
+        CommandArg::GlobalAdd(specs) => {
             if let Some(default_platform) = session.default_platform()? {
                 let platform = default_platform.as_default();
-                let command =
-                    PackageInstallCommand::new(name, args, platform, PackageManager::Npm)?;
-                return Ok(command.into());
+                let executors = specs
+                    .into_iter()
+                    .map(|spec| spec_to_executor(spec, args, &platform))
+                    .collect::<Fallible<Vec<Executor>>>()?;
+                return Ok(executors.into());
+            }
+
+            // With no default platform, Volta-managed tools (`node`, `npm`, `pnpm`, `yarn`) can
+            // still be installed internally — that path never needs a platform. Only registry
+            // packages and sources require one.
+            if specs.iter().all(|spec| !spec_requires_platform(spec)) {
+                let executors = specs
+                    .into_iter()
+                    .map(|tool| InternalInstallCommand::new(tool).into())
+                    .collect::<Vec<Executor>>();
+                return Ok(executors.into());
+            }
+
+            if strict_globals_enabled() {
+                // We would otherwise pass the install through to npm, which escapes Volta's shim
+                // management. In strict mode we refuse instead and point the user at the
+                // equivalent `volta install` invocation, built from the specs we actually matched
+                // so the suggestion can't disagree with the request (and so flag ordering like
+                // `npm install foo --global` doesn't skew it).
+                let package = specs
+                    .iter()
+                    .map(|spec| spec.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                return Err(ErrorKind::NoGlobalInstalls { package }.into());
             }
         }
-        CommandArg::GlobalAdd(tool) => {
-            return Ok(InternalInstallCommand::new(tool).into());
+        CommandArg::GlobalRemove(specs) => {
+            let executors = specs
+                .into_iter()
+                .map(|spec| spec_to_uninstall_executor(spec, args))
+                .collect::<Fallible<Vec<Executor>>>()?;
+            return Ok(executors.into());
+        }
+        CommandArg::GlobalUpdate(specs) => {
+            if let Some(default_platform) = session.default_platform()? {
+                let platform = default_platform.as_default();
+                // A bare `npm update -g` refreshes every package Volta manages; otherwise we
+                // reinstall the named packages, resolving the newest matching version and
+                // regenerating their shims. In both cases we synthesize a clean install command
+                // line rather than forwarding the `update`/`upgrade` args into the install path.
+                let executors = if specs.is_empty() {
+                    crate::tool::package::installed_packages()?
+                        .into_iter()
+                        .map(|name| {
+                            let install_args = reinstall_args(&name);
+                            PackageInstallCommand::new(
+                                name,
+                                &install_args,
+                                platform.clone(),
+                                PackageManager::Npm,
+                            )
+                            .map(Into::into)
+                        })
+                        .collect::<Fallible<Vec<Executor>>>()?
+                } else {
+                    specs
+                        .into_iter()
+                        .map(|spec| {
+                            let install_args = reinstall_args(&spec.to_string());
+                            spec_to_executor(spec, &install_args, &platform)
+                        })
+                        .collect::<Fallible<Vec<Executor>>>()?
+                };
+                return Ok(executors.into());
+            }
         }
         _ => {}
     }
@@ -38,6 +103,55 @@ pub(super) fn command(args: &[OsString], session: &mut Session) -> Fallible<Exec
     Ok(ToolCommand::new("npm", args, platform, ToolKind::Npm).into())
 }
 
+/// Construct a clean global-install command line for reinstalling `spec`, used by the update path
+/// so that the `update`/`upgrade` verb (and, for a bare update, the absence of a package) isn't
+/// forwarded into the install executor.
+fn reinstall_args(spec: &str) -> Vec<OsString> {
+    vec![
+        OsString::from("install"),
+        OsString::from("--global"),
+        OsString::from(spec),
+    ]
+}
+
+/// Whether installing `spec` requires a default platform.
+///
+/// Registry packages and non-registry sources are installed into the Volta data directory and
+/// need a platform to run the package manager; Volta-managed tools use internal install logic.
+fn spec_requires_platform(spec: &Spec) -> bool {
+    matches!(spec, Spec::Package(..) | Spec::Source(..))
+}
+
+/// Build the `Executor` for a single global-install spec, shared by the install and update paths.
+///
+/// Registry packages and non-registry sources are installed into the Volta data directory (with
+/// shims generated for their binaries), while Volta-managed tools use the internal install logic.
+fn spec_to_executor(spec: Spec, args: &[OsString], platform: &Platform) -> Fallible<Executor> {
+    match spec {
+        Spec::Package(name, _) => {
+            PackageInstallCommand::new(name, args, platform.clone(), PackageManager::Npm)
+                .map(Into::into)
+        }
+        Spec::Source(source) => {
+            PackageInstallCommand::from_source(source, args, platform.clone(), PackageManager::Npm)
+                .map(Into::into)
+        }
+        tool => Ok(InternalInstallCommand::new(tool).into()),
+    }
+}
+
+/// Build the `Executor` that removes a single global spec, mirroring [`spec_to_executor`].
+///
+/// Registry packages and non-registry sources delete their package image and shims, while
+/// Volta-managed tools use the internal uninstall logic.
+fn spec_to_uninstall_executor(spec: Spec, args: &[OsString]) -> Fallible<Executor> {
+    match spec {
+        Spec::Package(name, _) => PackageUninstallCommand::new(name, args).map(Into::into),
+        Spec::Source(source) => PackageUninstallCommand::from_source(source, args).map(Into::into),
+        tool => Ok(InternalUninstallCommand::new(tool).into()),
+    }
+}
+
 /// Determine the execution context (PATH and failure error message) for npm
 pub(super) fn execution_context(
     platform: Option<Platform>,
@@ -59,12 +173,13 @@ pub(super) fn execution_context(
     }
 }
 
-/// Using the provided arguments, check if the command is a valid global install
+/// Using the provided arguments, check if the command is a valid global install, uninstall, or
+/// update
 ///
 /// Note: We treat the case of `npm install --global <invalid package>` as _not_ a global install,
 /// to allow npm to show the appropriate error message.
 fn check_npm_install(args: &[OsString]) -> CommandArg {
-    // npm global installs will have `-g` or `--global` somewhere in the argument list
+    // npm global commands will have `-g` or `--global` somewhere in the argument list
     if !args.iter().any(|arg| arg == "-g" || arg == "--global") {
         return CommandArg::NotGlobalAdd;
     }
@@ -77,17 +192,76 @@ fn check_npm_install(args: &[OsString]) -> CommandArg {
     });
 
     // npm has aliases for "install" as a command: `i`, `install`, `add`, or `isntall`
+    // and for "uninstall": `uninstall`, `un`, `unlink`, `remove`, `rm`, or `r`
     // See https://github.com/npm/cli/blob/latest/lib/config/cmd-list.js
-    // Additionally, it is only a valid global install if there is a package to install
+    // Additionally, an install or uninstall is only a valid global command if there is a package
+    // to operate on; an update may name no package at all
     match (filtered.next(), filtered.next()) {
         (Some(cmd), Some(package))
             if cmd == "install" || cmd == "i" || cmd == "add" || cmd == "isntall" =>
         {
-            match Spec::try_from_str(&package.to_string_lossy()) {
-                Ok(tool) => CommandArg::GlobalAdd(tool),
-                Err(_) => CommandArg::NotGlobalAdd,
+            // Collect every positional spec so that batch installs
+            // (`npm i -g eslint prettier typescript`) all route through Volta. If any entry
+            // isn't a valid spec, defer the whole command to npm so it can show the error.
+            let mut specs = Vec::new();
+            for arg in std::iter::once(package).chain(filtered) {
+                match Spec::try_from_str(&arg.to_string_lossy()) {
+                    Ok(tool) => specs.push(tool),
+                    Err(_) => return CommandArg::NotGlobalAdd,
+                }
+            }
+            CommandArg::GlobalAdd(specs)
+        }
+        (Some(cmd), Some(package))
+            if cmd == "uninstall"
+                || cmd == "un"
+                || cmd == "unlink"
+                || cmd == "remove"
+                || cmd == "rm"
+                || cmd == "r" =>
+        {
+            // Collect every positional spec, mirroring the install path, so that batch removals
+            // (`npm uninstall -g eslint prettier`) all round-trip through Volta rather than
+            // dropping the tail. If any entry isn't a valid spec, defer to npm.
+            let mut specs = Vec::new();
+            for arg in std::iter::once(package).chain(filtered) {
+                match Spec::try_from_str(&arg.to_string_lossy()) {
+                    Ok(tool) => specs.push(tool),
+                    Err(_) => return CommandArg::NotGlobalAdd,
+                }
+            }
+            CommandArg::GlobalRemove(specs)
+        }
+        (Some(cmd), maybe_package)
+            if cmd == "update"
+                || cmd == "up"
+                || cmd == "upgrade"
+                || cmd == "udpate" =>
+        {
+            // `npm update -g` may name zero or more packages; a bare invocation refreshes
+            // everything Volta manages, so an empty spec list is still a valid global update.
+            let mut specs = Vec::new();
+            for arg in maybe_package.into_iter().chain(filtered) {
+                match Spec::try_from_str(&arg.to_string_lossy()) {
+                    Ok(tool) => specs.push(tool),
+                    Err(_) => return CommandArg::NotGlobalAdd,
+                }
             }
+            CommandArg::GlobalUpdate(specs)
         }
         _ => CommandArg::NotGlobalAdd,
     }
 }
+
+/// Whether Volta has been configured to refuse global installs that can't be managed
+///
+/// This is opt-in, enabled by setting the `VOLTA_STRICT_GLOBALS` environment variable to `1` or
+/// `true`. When enabled, an intercepted global install with no usable default platform
+/// is rejected (rather than silently handed off to npm) so that Volta's ownership of globals
+/// stays explicit.
+fn strict_globals_enabled() -> bool {
+    matches!(
+        std::env::var_os("VOLTA_STRICT_GLOBALS"),
+        Some(value) if value == "1" || value == "true"
+    )
+}
@@ -0,0 +1,152 @@
+//! Types describing the tools Volta can manage.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::error::Fallible;
+use crate::version::VersionSpec;
+
+pub mod package;
+
+/// The source for a global package install that does not come from the npm registry.
+///
+/// This mirrors the source selection that `cargo install` exposes: a git repository (optionally
+/// pinned to a branch, tag, or revision), a local path, or a remote archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSource {
+    /// A git repository, e.g. `github:user/repo`, `git+https://…`, or `…#<ref>`
+    Git(String),
+    /// A remote archive (tarball) URL
+    Remote(String),
+    /// A package on the local filesystem, e.g. `./my-cli`
+    File(PathBuf),
+}
+
+impl fmt::Display for PackageSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PackageSource::Git(spec) | PackageSource::Remote(spec) => f.write_str(spec),
+            PackageSource::File(path) => f.write_str(&path.to_string_lossy()),
+        }
+    }
+}
+
+/// A requested tool, parsed from a command-line argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Spec {
+    Node(VersionSpec),
+    Npm(VersionSpec),
+    Pnpm(VersionSpec),
+    Yarn(VersionSpec),
+    /// A registry package, identified by name and an optional version requirement
+    Package(String, VersionSpec),
+    /// A package installed from a non-registry source (git, tarball, or local path)
+    Source(PackageSource),
+}
+
+impl Spec {
+    /// Parse a tool argument into a `Spec`.
+    ///
+    /// Volta-managed tools (`node`, `npm`, `pnpm`, `yarn`) are recognized by name. Anything that
+    /// looks like a git, remote-archive, or local-path reference becomes a [`Spec::Source`];
+    /// everything else is treated as a registry [`Spec::Package`].
+    pub fn try_from_str(tool: &str) -> Fallible<Self> {
+        if let Some(source) = parse_source(tool) {
+            return Ok(Spec::Source(source));
+        }
+
+        let (name, version) = split_requirement(tool);
+        let version = VersionSpec::parse(version)?;
+
+        Ok(match name {
+            "node" => Spec::Node(version),
+            "npm" => Spec::Npm(version),
+            "pnpm" => Spec::Pnpm(version),
+            "yarn" => Spec::Yarn(version),
+            other => Spec::Package(other.to_string(), version),
+        })
+    }
+}
+
+impl fmt::Display for Spec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Spec::Node(_) => f.write_str("node"),
+            Spec::Npm(_) => f.write_str("npm"),
+            Spec::Pnpm(_) => f.write_str("pnpm"),
+            Spec::Yarn(_) => f.write_str("yarn"),
+            Spec::Package(name, _) => f.write_str(name),
+            Spec::Source(source) => source.fmt(f),
+        }
+    }
+}
+
+/// Split a `name@version` argument into its name and version-requirement halves, taking care not
+/// to treat a leading `@` (a scoped package) as a separator.
+fn split_requirement(tool: &str) -> (&str, &str) {
+    match tool.rfind('@') {
+        Some(index) if index > 0 => (&tool[..index], &tool[index + 1..]),
+        _ => (tool, ""),
+    }
+}
+
+/// Classify non-registry package references. Returns `None` for plain registry specs so that they
+/// fall through to the normal `name@version` handling.
+fn parse_source(value: &str) -> Option<PackageSource> {
+    // Local paths: explicit relative/absolute paths or a `file:` specifier
+    if value.starts_with("./")
+        || value.starts_with("../")
+        || value.starts_with('/')
+        || value.starts_with("~/")
+    {
+        return Some(PackageSource::File(PathBuf::from(value)));
+    }
+    if let Some(path) = value.strip_prefix("file:") {
+        return Some(PackageSource::File(PathBuf::from(path)));
+    }
+
+    // Git references: explicit `git+`/`git:` URLs, hosting shorthands, `.git` suffixes, or any
+    // URL carrying a `#<ref>` fragment.
+    if value.starts_with("git+")
+        || value.starts_with("git:")
+        || value.starts_with("github:")
+        || value.starts_with("gitlab:")
+        || value.starts_with("bitbucket:")
+        || value.starts_with("gist:")
+        || value.ends_with(".git")
+        || (value.contains("://") && value.contains('#'))
+    {
+        return Some(PackageSource::Git(value.to_string()));
+    }
+
+    // Remote archives: any http(s) URL that isn't an explicit git reference (handled above by the
+    // `git+`, `.git` and `#<ref>` checks). We can't reliably tell a tarball from its extension alone — archives
+    // are served without `.tgz`/`.tar.gz` (query strings, content-disposition, etc.) — so default
+    // unknown remote URLs to an archive rather than a git clone.
+    if value.starts_with("http://") || value.starts_with("https://") {
+        return Some(PackageSource::Remote(value.to_string()));
+    }
+
+    // `user/repo` shorthand resolves to a GitHub repository, but a scoped package (`@scope/name`)
+    // must not be mistaken for one.
+    if !value.starts_with('@') && is_repo_shorthand(value) {
+        return Some(PackageSource::Git(value.to_string()));
+    }
+
+    None
+}
+
+/// Whether `value` looks like a bare `user/repo` GitHub shorthand (exactly one `/`, no version
+/// requirement, and no whitespace).
+fn is_repo_shorthand(value: &str) -> bool {
+    let mut parts = value.split('/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(owner), Some(repo), None) => {
+            !owner.is_empty()
+                && !repo.is_empty()
+                && !value.contains('@')
+                && !value.contains(char::is_whitespace)
+        }
+        _ => false,
+    }
+}
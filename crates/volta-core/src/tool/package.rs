@@ -0,0 +1,175 @@
+//! Installation and management of globally-installed packages.
+
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use super::PackageSource;
+use crate::error::{Context, ErrorKind, Fallible};
+use crate::layout::volta_home;
+use crate::platform::Image;
+use crate::{fs, shim};
+
+/// The package manager used to perform a global install.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+}
+
+impl PackageManager {
+    /// Point a global install command at a staging directory, so that the package is installed
+    /// somewhere Volta controls rather than into the package manager's own global prefix.
+    fn setup_global_command(self, command: &mut Command, staging: &Path) {
+        match self {
+            PackageManager::Npm | PackageManager::Pnpm => {
+                command.env("npm_config_prefix", staging);
+            }
+            PackageManager::Yarn => {
+                command.env("YARN_GLOBAL_FOLDER", staging);
+            }
+        }
+    }
+}
+
+/// What a global package install is sourced from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InstallTarget {
+    /// A registry package, identified by name
+    Registry(String),
+    /// A non-registry source (git, tarball, or local path)
+    Source(PackageSource),
+}
+
+impl InstallTarget {
+    /// The argument that identifies this target, used for diagnostics.
+    fn arg(&self) -> String {
+        match self {
+            InstallTarget::Registry(name) => name.clone(),
+            InstallTarget::Source(source) => source.to_string(),
+        }
+    }
+}
+
+/// Performs a global package install into a Volta-controlled staging directory, then persists the
+/// resulting image and generates shims for its binaries.
+pub struct DirectInstall {
+    staging: TempDir,
+    target: InstallTarget,
+    manager: PackageManager,
+}
+
+impl DirectInstall {
+    /// Create a `DirectInstall` for a registry package.
+    pub fn new(name: String, manager: PackageManager) -> Fallible<DirectInstall> {
+        Ok(DirectInstall {
+            staging: staging_directory()?,
+            target: InstallTarget::Registry(name),
+            manager,
+        })
+    }
+
+    /// Create a `DirectInstall` for a non-registry source (git, tarball, or local path).
+    pub fn from_source(source: PackageSource, manager: PackageManager) -> Fallible<DirectInstall> {
+        Ok(DirectInstall {
+            staging: staging_directory()?,
+            target: InstallTarget::Source(source),
+            manager,
+        })
+    }
+
+    /// Redirect the install command at the staging directory managed by Volta.
+    pub fn setup_command(&self, command: &mut Command) {
+        self.manager
+            .setup_global_command(command, self.staging.path());
+    }
+
+    /// Finalize the install, copying the staged package image into the Volta data directory and
+    /// regenerating shims for its binaries.
+    pub fn complete_install(self, image: &Image) -> Fallible<()> {
+        let name = persist_staged_package(&self.target, self.staging.path(), image)
+            .with_context(|| ErrorKind::PackageInstallFailed {
+                package: self.target.arg(),
+            })?;
+        shim::regenerate_shims_for_package(&name)
+    }
+}
+
+/// The package an uninstall command should remove.
+pub enum UninstallTarget {
+    /// A package identified by its registry name
+    Package(String),
+    /// A package installed from a non-registry source, identified after the fact by its source
+    Source(PackageSource),
+}
+
+/// Remove a Volta-managed global package: delete its image from the Volta data directory and the
+/// shims that were generated for it.
+pub fn uninstall(target: &UninstallTarget) -> Fallible<()> {
+    let name = match target {
+        UninstallTarget::Package(name) => name.clone(),
+        UninstallTarget::Source(source) => {
+            fs::read_source_package_name(source).with_context(|| {
+                ErrorKind::PackageUninstallFailed {
+                    package: source.to_string(),
+                }
+            })?
+        }
+    };
+
+    remove_package_image(&name).with_context(|| ErrorKind::PackageUninstallFailed {
+        package: name.clone(),
+    })?;
+    shim::delete_shims_for_package(&name)
+}
+
+/// Enumerate the names of every package Volta currently manages.
+pub fn installed_packages() -> Fallible<Vec<String>> {
+    let home = volta_home()?;
+    let mut names = Vec::new();
+
+    for entry in fs::read_dir_eager(home.package_image_root_dir())
+        .with_context(|| ErrorKind::ReadPackagesError)?
+    {
+        if entry.file_type().is_dir() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+
+    Ok(names)
+}
+
+/// Move the staged package image into the Volta data directory, returning the package name.
+fn persist_staged_package(
+    target: &InstallTarget,
+    staging: &Path,
+    image: &Image,
+) -> Fallible<String> {
+    let name = match target {
+        InstallTarget::Registry(name) => name.clone(),
+        InstallTarget::Source(source) => resolve_source_name(source)?,
+    };
+    fs::persist_staged_image(staging, &name, image)?;
+    Ok(name)
+}
+
+/// Read the installed package name for a source install from its manifest.
+fn resolve_source_name(source: &PackageSource) -> Fallible<String> {
+    fs::read_source_package_name(source).with_context(|| ErrorKind::PackageInstallFailed {
+        package: source.to_string(),
+    })
+}
+
+/// Remove the persisted image for a package from the Volta data directory.
+fn remove_package_image(name: &str) -> Fallible<()> {
+    let home = volta_home()?;
+    fs::remove_dir_if_exists(home.package_image_dir(name))
+}
+
+/// Create a fresh staging directory under Volta's temp area for a global install.
+fn staging_directory() -> Fallible<TempDir> {
+    let home = volta_home()?;
+    fs::create_staging_dir(home.tmp_dir())
+}